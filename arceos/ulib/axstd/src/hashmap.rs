@@ -2,27 +2,40 @@ extern crate alloc;
 
 use alloc::boxed::Box;
 use alloc::vec::Vec;
-use core::hash::{Hash, Hasher};
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
 use core::mem;
 
-// 假设的hash函数，你可以替换为你自己的实现
-fn hash<T: Hash>(key: &T) -> usize {
-    use core::hash::SipHasher;
-    let mut hasher = SipHasher::new();
-    key.hash(&mut hasher);
-    hasher.finish() as usize
+// 默认哈希器：构建一个 SipHasher，作为 `S` 的缺省实现
+#[derive(Clone, Default)]
+pub struct DefaultHasher;
+
+impl BuildHasher for DefaultHasher {
+    #[allow(deprecated)]
+    type Hasher = core::hash::SipHasher;
+
+    #[allow(deprecated)]
+    fn build_hasher(&self) -> Self::Hasher {
+        core::hash::SipHasher::new()
+    }
+}
+
+// 用给定的哈希器算出键的原始哈希值
+fn hash_key<Q: Hash + ?Sized, S: BuildHasher>(hasher: &S, key: &Q) -> usize {
+    hasher.hash_one(key) as usize
 }
 
 // HashMap的条目
-struct Entry<K, V> {
+struct Node<K, V> {
     key: K,
     value: V,
-    next: Option<Box<Entry<K, V>>>,
+    next: Option<Box<Node<K, V>>>,
 }
 
-impl<K, V> Entry<K, V> {
+impl<K, V> Node<K, V> {
     fn new(key: K, value: V) -> Self {
-        Entry {
+        Node {
             key,
             value,
             next: None,
@@ -31,34 +44,60 @@ impl<K, V> Entry<K, V> {
 }
 
 // HashMap结构
-pub struct HashMap<K, V> {
-    buckets: Vec<Option<Box<Entry<K, V>>>>,
+pub struct HashMap<K, V, S = DefaultHasher> {
+    buckets: Vec<Option<Box<Node<K, V>>>>,
     size: usize,
     capacity: usize,
+    hasher: S,
 }
 
 // 不可变迭代器
-pub struct Iter<'a, K, V> {
-    map: &'a HashMap<K, V>,
+pub struct Iter<'a, K, V, S = DefaultHasher> {
+    map: &'a HashMap<K, V, S>,
     bucket_idx: usize,
-    current: Option<&'a Entry<K, V>>,
+    current: Option<&'a Node<K, V>>,
 }
 
-impl<K, V> HashMap<K, V>
+impl<K, V> HashMap<K, V, DefaultHasher>
 where
-    K: Eq + Hash + Clone,
-    V: Clone
+    K: Eq + Hash,
 {
-    // 创建一个新的HashMap
+    // 创建一个使用默认哈希器的HashMap
     pub fn new() -> Self {
-        let initial_capacity = 16;
-        let mut buckets = Vec::with_capacity(initial_capacity);
-        buckets.resize_with(initial_capacity, || None);
+        Self::with_hasher(DefaultHasher)
+    }
+}
+
+impl<K, V> Default for HashMap<K, V, DefaultHasher>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    // 使用给定哈希器创建一个新的HashMap
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(16, hasher)
+    }
+
+    // 使用给定容量与哈希器创建一个新的HashMap
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        let capacity = capacity.max(1);
+        let mut buckets = Vec::with_capacity(capacity);
+        buckets.resize_with(capacity, || None);
 
         HashMap {
             buckets,
             size: 0,
-            capacity: initial_capacity,
+            capacity,
+            hasher,
         }
     }
 
@@ -67,53 +106,38 @@ where
         if self.size >= self.capacity * 3 / 4 {
             self.resize();
         }
-    
-        let index = hash(&key) % self.capacity;
-        let mut entry = self.buckets[index].take();
-    
-        // 检查是否已存在相同的 key
-        let mut prev = None;
-        let mut current = entry;
-        let mut old_value = None;
-    
-        while let Some(mut boxed_entry) = current {
-            if boxed_entry.key == key {
-                old_value = Some(mem::replace(&mut boxed_entry.value, value.clone()));
-                current = boxed_entry.next.take();
-                break;
+
+        let index = hash_key(&self.hasher, &key) % self.capacity;
+
+        // 若已存在相同的 key，替换其值并返回旧值
+        let mut current = self.buckets[index].as_mut();
+        while let Some(node) = current {
+            if node.key == key {
+                return Some(mem::replace(&mut node.value, value));
             }
-            prev = Some(boxed_entry);
-            current = prev.as_mut().unwrap().next.take();
-        }
-    
-        // 重建链表
-        if let Some(mut p) = prev {
-            p.next = current;
-            entry = Some(p);
-        } else {
-            entry = current;
-        }
-    
-        // 如果 key 不存在，添加新条目
-        if old_value.is_none() {
-            let mut new_entry = Box::new(Entry::new(key, value));
-            new_entry.next = entry;
-            self.buckets[index] = Some(new_entry);
-            self.size += 1;
-        } else {
-            self.buckets[index] = entry;
+            current = node.next.as_mut();
         }
-    
-        old_value
+
+        // 否则在桶头插入新条目
+        let mut node = Box::new(Node::new(key, value));
+        node.next = self.buckets[index].take();
+        self.buckets[index] = Some(node);
+        self.size += 1;
+
+        None
     }
 
-    // 获取值
-    pub fn get(&self, key: &K) -> Option<&V> {
-        let index = hash(key) % self.capacity;
+    // 获取值，允许用借用形式的键（如用 &str 查询 String 键）
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = hash_key(&self.hasher, key) % self.capacity;
         let mut current = self.buckets[index].as_ref();
 
         while let Some(entry) = current {
-            if &entry.key == key {
+            if entry.key.borrow() == key {
                 return Some(&entry.value);
             }
             current = entry.next.as_ref();
@@ -122,13 +146,17 @@ where
         None
     }
 
-    // 获取可变值
-    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
-        let index = hash(key) % self.capacity;
+    // 获取可变值，允许用借用形式的键
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = hash_key(&self.hasher, key) % self.capacity;
         let mut current = self.buckets[index].as_mut();
 
         while let Some(entry) = current {
-            if &entry.key == key {
+            if entry.key.borrow() == key {
                 return Some(&mut entry.value);
             }
             current = entry.next.as_mut();
@@ -137,16 +165,20 @@ where
         None
     }
 
-    // 移除键值对
-    pub fn remove(&mut self, key: &K) -> Option<V> {
-        let index = hash(key) % self.capacity;
+    // 移除键值对，允许用借用形式的键
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = hash_key(&self.hasher, key) % self.capacity;
         let mut entry = self.buckets[index].take();
         let mut prev = None;
         let mut current = entry;
         let mut removed = None;
 
         while let Some(mut boxed_entry) = current {
-            if &boxed_entry.key == key {
+            if boxed_entry.key.borrow() == key {
                 removed = Some(boxed_entry.value);
                 current = boxed_entry.next.take();
                 self.size -= 1;
@@ -174,23 +206,53 @@ where
         let mut new_buckets = Vec::with_capacity(new_capacity);
         new_buckets.resize_with(new_capacity, || None);
 
-        for bucket in self.buckets.drain(..) {
+        let old_buckets = mem::replace(&mut self.buckets, new_buckets);
+        for bucket in old_buckets {
             let mut current = bucket;
             while let Some(mut entry) = current {
                 let next = entry.next.take();
-                let index = hash(&entry.key) % new_capacity;
-                
-                entry.next = new_buckets[index].take();
-                new_buckets[index] = Some(entry);
-                
+                let index = hash_key(&self.hasher, &entry.key) % new_capacity;
+
+                entry.next = self.buckets[index].take();
+                self.buckets[index] = Some(entry);
+
                 current = next;
             }
         }
 
-        self.buckets = new_buckets;
         self.capacity = new_capacity;
     }
 
+    // 获取键对应的条目，用于“存在则修改，否则插入”的场景
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        // 先触发扩容，这样返回的借用始终指向最终的桶数组
+        if self.size >= self.capacity * 3 / 4 {
+            self.resize();
+        }
+
+        let index = hash_key(&self.hasher, &key) % self.capacity;
+
+        // 单次遍历定位：命中则交出该节点值的可变引用，走到链尾则是空位。
+        // 这里用裸指针把“命中”分支返回的借用与遍历游标解耦，从而绕开借用检查器
+        // 对“循环内返回借用后又重新借用桶”的保守限制。
+        let mut current = self.buckets[index].as_mut();
+        while let Some(node) = current {
+            if node.key == key {
+                let value: *mut V = &mut node.value;
+                return Entry::Occupied(OccupiedEntry {
+                    value: unsafe { &mut *value },
+                });
+            }
+            current = node.next.as_mut();
+        }
+
+        Entry::Vacant(VacantEntry {
+            key,
+            bucket: &mut self.buckets[index],
+            size: &mut self.size,
+        })
+    }
+
     // 获取大小
     pub fn len(&self) -> usize {
         self.size
@@ -202,17 +264,112 @@ where
     }
 
     // 返回不可变迭代器
-    pub fn iter(&self) -> Iter<K, V> {
+    pub fn iter(&self) -> Iter<'_, K, V, S> {
         Iter {
             map: self,
             bucket_idx: 0,
             current: None,
         }
     }
+
+    // 返回可变迭代器
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            buckets: self.buckets.iter_mut(),
+            current: None,
+        }
+    }
+
+    // 清空映射，并以迭代器形式交出其中所有键值对
+    pub fn drain(&mut self) -> Drain<K, V> {
+        let mut fresh = Vec::with_capacity(self.capacity);
+        fresh.resize_with(self.capacity, || None);
+        let old = mem::replace(&mut self.buckets, fresh);
+        self.size = 0;
+        Drain {
+            buckets: old.into_iter(),
+            current: None,
+        }
+    }
+}
+
+// entry API：桶中某个键位置的视图
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+// 已存在的条目，持有其值的可变引用
+pub struct OccupiedEntry<'a, V> {
+    value: &'a mut V,
+}
+
+// 尚不存在的条目，持有桶头与计数的可变引用
+pub struct VacantEntry<'a, K, V> {
+    key: K,
+    bucket: &'a mut Option<Box<Node<K, V>>>,
+    size: &'a mut usize,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    // 在桶头插入新节点并返回其值的可变引用
+    pub fn insert(self, value: V) -> &'a mut V {
+        let mut node = Box::new(Node::new(self.key, value));
+        node.next = self.bucket.take();
+        *self.bucket = Some(node);
+        *self.size += 1;
+        &mut self.bucket.as_mut().unwrap().value
+    }
+}
+
+impl<'a, V> OccupiedEntry<'a, V> {
+    // 获取值的可变引用
+    pub fn get_mut(&mut self) -> &mut V {
+        self.value
+    }
+
+    // 消费条目并返回其值的可变引用
+    pub fn into_mut(self) -> &'a mut V {
+        self.value
+    }
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    // 存在则返回已有值，否则插入给定默认值
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.value,
+            Entry::Vacant(e) => e.insert(default),
+        }
+    }
+
+    // 存在则返回已有值，否则用闭包生成默认值插入
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.value,
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    // 存在则返回已有值，否则插入 V 的默认值
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    // 若条目已存在，先用闭包就地修改其值
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(ref mut e) = self {
+            f(&mut *e.value);
+        }
+        self
+    }
 }
 
 // 实现不可变迭代器
-impl<'a, K, V> Iterator for Iter<'a, K, V> {
+impl<'a, K, V, S> Iterator for Iter<'a, K, V, S> {
     type Item = (&'a K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -220,7 +377,7 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
             // 如果当前条目存在，返回它并移动到下一个
             if let Some(entry) = self.current {
                 let result = (&entry.key, &entry.value);
-                self.current = entry.next.as_ref().map(|b| &**b);
+                self.current = entry.next.as_deref();
                 return Some(result);
             }
 
@@ -229,8 +386,241 @@ impl<'a, K, V> Iterator for Iter<'a, K, V> {
                 return None;
             }
 
-            self.current = self.map.buckets[self.bucket_idx].as_ref().map(|b| &**b);
+            self.current = self.map.buckets[self.bucket_idx].as_deref();
             self.bucket_idx += 1;
         }
     }
 }
+
+// 可变迭代器：逐桶遍历，沿链表交出每个节点值的可变引用
+pub struct IterMut<'a, K, V> {
+    buckets: core::slice::IterMut<'a, Option<Box<Node<K, V>>>>,
+    current: Option<&'a mut Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(node) = self.current.take() {
+                // 拆分可变借用：键、值与 next 指向互不相交的字段
+                let Node { key, value, next } = node;
+                self.current = next.as_deref_mut();
+                return Some((&*key, value));
+            }
+
+            match self.buckets.next() {
+                Some(slot) => self.current = slot.as_deref_mut(),
+                None => return None,
+            }
+        }
+    }
+}
+
+// 拥有所有权的迭代器，消费整个映射
+pub struct IntoIter<K, V> {
+    buckets: alloc::vec::IntoIter<Option<Box<Node<K, V>>>>,
+    current: Option<Box<Node<K, V>>>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(node) = self.current.take() {
+                let Node { key, value, next } = *node;
+                self.current = next;
+                return Some((key, value));
+            }
+
+            match self.buckets.next() {
+                Some(slot) => self.current = slot,
+                None => return None,
+            }
+        }
+    }
+}
+
+// drain 迭代器：语义同 IntoIter，但映射本身保留（已被清空）
+pub struct Drain<K, V> {
+    buckets: alloc::vec::IntoIter<Option<Box<Node<K, V>>>>,
+    current: Option<Box<Node<K, V>>>,
+}
+
+impl<K, V> Iterator for Drain<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(node) = self.current.take() {
+                let Node { key, value, next } = *node;
+                self.current = next;
+                return Some((key, value));
+            }
+
+            match self.buckets.next() {
+                Some(slot) => self.current = slot,
+                None => return None,
+            }
+        }
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            buckets: self.buckets.into_iter(),
+            current: None,
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, S>;
+
+    fn into_iter(self) -> Iter<'a, K, V, S> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = HashMap::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod entry_tests {
+    use super::*;
+
+    #[test]
+    fn or_insert_vacant_then_occupied() {
+        let mut map: HashMap<&str, i32> = HashMap::new();
+        assert_eq!(*map.entry("a").or_insert(1), 1);
+        // 已存在时返回既有值，不覆盖
+        assert_eq!(*map.entry("a").or_insert(99), 1);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn and_modify_then_or_insert() {
+        let mut map: HashMap<&str, i32> = HashMap::new();
+        *map.entry("x").and_modify(|v| *v += 10).or_insert(5) += 0;
+        assert_eq!(map.get("x"), Some(&5));
+        *map.entry("x").and_modify(|v| *v += 10).or_insert(5) += 0;
+        assert_eq!(map.get("x"), Some(&15));
+    }
+
+    #[test]
+    fn or_default_inserts_default() {
+        let mut map: HashMap<&str, i32> = HashMap::new();
+        *map.entry("n").or_default() += 1;
+        assert_eq!(map.get("n"), Some(&1));
+    }
+}
+
+#[cfg(test)]
+mod iter_tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn sorted<K: Ord + Clone, V: Clone>(mut v: Vec<(K, V)>) -> Vec<(K, V)> {
+        v.sort_by(|a, b| a.0.cmp(&b.0));
+        v
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut map: HashMap<i32, i32> = [(1, 1), (2, 2)].into_iter().collect();
+        map.extend([(3, 3), (1, 10)]);
+        let got = sorted(map.iter().map(|(&k, &v)| (k, v)).collect());
+        assert_eq!(got, [(1, 10), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn iter_mut_updates_in_place() {
+        let mut map: HashMap<i32, i32> = [(1, 1), (2, 2)].into_iter().collect();
+        for (_, v) in map.iter_mut() {
+            *v *= 10;
+        }
+        let got = sorted(map.iter().map(|(&k, &v)| (k, v)).collect());
+        assert_eq!(got, [(1, 10), (2, 20)]);
+    }
+
+    #[test]
+    fn into_iter_consumes_all() {
+        let map: HashMap<i32, i32> = [(1, 1), (2, 2), (3, 3)].into_iter().collect();
+        let got = sorted(map.into_iter().collect());
+        assert_eq!(got, [(1, 1), (2, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn drain_empties_but_keeps_usable() {
+        let mut map: HashMap<i32, i32> = [(1, 1), (2, 2)].into_iter().collect();
+        let drained = sorted(map.drain().collect());
+        assert_eq!(drained, [(1, 1), (2, 2)]);
+        assert!(map.is_empty());
+        // drain 之后映射仍可继续使用
+        map.insert(9, 9);
+        assert_eq!(map.get(&9), Some(&9));
+    }
+}
+
+#[cfg(test)]
+mod borrow_tests {
+    use super::*;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    #[test]
+    fn lookup_string_keys_with_str() {
+        let mut map: HashMap<String, i32> = HashMap::new();
+        map.insert("hello".to_string(), 1);
+        // 无需构造 String 即可用 &str 查询
+        assert_eq!(map.get("hello"), Some(&1));
+        assert!(map.get_mut("hello").is_some());
+        assert_eq!(map.remove("hello"), Some(1));
+        assert!(map.get("hello").is_none());
+    }
+}
@@ -0,0 +1,197 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::mem;
+
+use crate::hashmap::HashMap;
+
+// 按插入顺序保存的条目
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+// 记住插入顺序并支持按位置访问的映射
+pub struct IndexMap<K, V> {
+    // 按插入顺序排列的条目
+    entries: Vec<Entry<K, V>>,
+    // key -> 在 entries 中的位置
+    map: HashMap<K, usize>,
+}
+
+impl<K, V> IndexMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    // 创建一个空的IndexMap
+    pub fn new() -> Self {
+        IndexMap {
+            entries: Vec::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    // 插入键值对；已存在则覆盖并返回旧值，新键则追加到末尾
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&idx) = self.map.get(&key) {
+            return Some(mem::replace(&mut self.entries[idx].value, value));
+        }
+        let idx = self.entries.len();
+        self.entries.push(Entry {
+            key: key.clone(),
+            value,
+        });
+        self.map.insert(key, idx);
+        None
+    }
+
+    // 获取值
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&idx| &self.entries[idx].value)
+    }
+
+    // 按位置获取键值对
+    pub fn get_index(&self, i: usize) -> Option<(&K, &V)> {
+        self.entries.get(i).map(|e| (&e.key, &e.value))
+    }
+
+    // 获取位置、键与值
+    pub fn get_full<Q>(&self, key: &Q) -> Option<(usize, &K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &idx = self.map.get(key)?;
+        let entry = &self.entries[idx];
+        Some((idx, &entry.key, &entry.value))
+    }
+
+    // O(1) 删除：把末尾条目换到被删位置，并更新被移动键的下标。
+    // 注意这会打乱插入顺序，若要保序请用 `shift_remove`。
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.map.remove(key)?;
+        let removed = self.entries.swap_remove(idx);
+        if idx < self.entries.len() {
+            // 原本在末尾的条目现在落到了 idx
+            let moved_key = self.entries[idx].key.clone();
+            self.map.insert(moved_key, idx);
+        }
+        Some(removed.value)
+    }
+
+    // O(n) 删除：保持其余条目的插入顺序，但需整体前移并更新下标
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.map.remove(key)?;
+        let removed = self.entries.remove(idx);
+        for i in idx..self.entries.len() {
+            let moved_key = self.entries[i].key.clone();
+            self.map.insert(moved_key, i);
+        }
+        Some(removed.value)
+    }
+
+    // 条目数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    // 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // 按插入顺序遍历
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+}
+
+impl<K, V> Default for IndexMap<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 按插入顺序的迭代器
+pub struct Iter<'a, K, V> {
+    inner: core::slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|e| (&e.key, &e.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let mut map: IndexMap<&str, i32> = IndexMap::new();
+        map.insert("c", 3);
+        map.insert("a", 1);
+        map.insert("b", 2);
+        // 覆盖已有键不改变其位置
+        map.insert("a", 10);
+        let order: Vec<(&str, i32)> = map.iter().map(|(&k, &v)| (k, v)).collect();
+        assert_eq!(order, [("c", 3), ("a", 10), ("b", 2)]);
+    }
+
+    #[test]
+    fn positional_access() {
+        let mut map: IndexMap<&str, i32> = IndexMap::new();
+        map.insert("x", 1);
+        map.insert("y", 2);
+        assert_eq!(map.get_index(1), Some((&"y", &2)));
+        assert_eq!(map.get_full("x"), Some((0, &"x", &1)));
+    }
+
+    #[test]
+    fn swap_remove_perturbs_order() {
+        let mut map: IndexMap<i32, i32> = IndexMap::new();
+        map.insert(1, 1);
+        map.insert(2, 2);
+        map.insert(3, 3);
+        // 删除 0 号位，末尾的 3 被换到前面
+        assert_eq!(map.swap_remove(&1), Some(1));
+        let order: Vec<i32> = map.iter().map(|(&k, _)| k).collect();
+        assert_eq!(order, [3, 2]);
+        assert_eq!(map.get_full(&3), Some((0, &3, &3)));
+    }
+
+    #[test]
+    fn shift_remove_preserves_order() {
+        let mut map: IndexMap<i32, i32> = IndexMap::new();
+        map.insert(1, 1);
+        map.insert(2, 2);
+        map.insert(3, 3);
+        assert_eq!(map.shift_remove(&1), Some(1));
+        let order: Vec<i32> = map.iter().map(|(&k, _)| k).collect();
+        assert_eq!(order, [2, 3]);
+        assert_eq!(map.get_full(&3), Some((1, &3, &3)));
+    }
+}
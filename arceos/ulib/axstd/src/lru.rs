@@ -0,0 +1,276 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::mem;
+
+use crate::hashmap::HashMap;
+
+// 侵入式双向链表的节点，存放在 slab 中，prev/next 用下标而非指针表示
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+// 固定容量的 LRU 缓存：HashMap 负责 O(1) 查找，slab 上的双向链表负责记录访问顺序
+pub struct LruCache<K, V> {
+    // key -> slab 下标
+    map: HashMap<K, usize>,
+    // 节点槽位
+    slab: Vec<Node<K, V>>,
+    // 最近使用端与最久未使用端
+    head: Option<usize>,
+    tail: Option<usize>,
+    cap: usize,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    // 创建一个容量为 cap 的 LRU 缓存
+    pub fn new(cap: usize) -> Self {
+        LruCache {
+            map: HashMap::new(),
+            slab: Vec::with_capacity(cap),
+            head: None,
+            tail: None,
+            cap,
+        }
+    }
+
+    // 将节点从链表中摘除
+    fn unlink(&mut self, idx: usize) {
+        let (prev, next) = {
+            let node = &self.slab[idx];
+            (node.prev, node.next)
+        };
+        match prev {
+            Some(p) => self.slab[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.slab[n].prev = prev,
+            None => self.tail = prev,
+        }
+        self.slab[idx].prev = None;
+        self.slab[idx].next = None;
+    }
+
+    // 将节点接到链表头（最近使用端）
+    fn push_front(&mut self, idx: usize) {
+        self.slab[idx].prev = None;
+        self.slab[idx].next = self.head;
+        if let Some(h) = self.head {
+            self.slab[h].prev = Some(idx);
+        }
+        self.head = Some(idx);
+        if self.tail.is_none() {
+            self.tail = Some(idx);
+        }
+    }
+
+    // 插入或更新键值对；若是已存在的键返回其旧值
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&idx) = self.map.get(&key) {
+            let old = mem::replace(&mut self.slab[idx].value, value);
+            self.unlink(idx);
+            self.push_front(idx);
+            return Some(old);
+        }
+
+        if self.cap == 0 {
+            return None;
+        }
+
+        if self.len() == self.cap {
+            // 容量已满：淘汰尾部节点，复用它的槽位
+            let tail = self.tail.unwrap();
+            self.unlink(tail);
+            // 复用槽位前务必先把旧键从 map 中移除
+            let old_key = mem::replace(&mut self.slab[tail].key, key.clone());
+            self.map.remove(&old_key);
+            self.slab[tail].value = value;
+            self.push_front(tail);
+            self.map.insert(key, tail);
+        } else {
+            let idx = self.slab.len();
+            self.slab.push(Node {
+                key: key.clone(),
+                value,
+                prev: None,
+                next: None,
+            });
+            self.push_front(idx);
+            self.map.insert(key, idx);
+        }
+
+        None
+    }
+
+    // 查询并将其提升为最近使用
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(&idx) = self.map.get(key) {
+            self.unlink(idx);
+            self.push_front(idx);
+            Some(&self.slab[idx].value)
+        } else {
+            None
+        }
+    }
+
+    // 查询可变值并将其提升为最近使用
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(&idx) = self.map.get(key) {
+            self.unlink(idx);
+            self.push_front(idx);
+            Some(&mut self.slab[idx].value)
+        } else {
+            None
+        }
+    }
+
+    // 查询但不改变访问顺序
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|&idx| &self.slab[idx].value)
+    }
+
+    // 移除并返回某个键对应的值
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let idx = self.map.remove(key)?;
+        self.unlink(idx);
+
+        // 用 swap_remove 回收槽位；若有节点被移动，需要修正其下标
+        let removed = self.slab.swap_remove(idx);
+        if idx < self.slab.len() {
+            // 原本位于末尾的节点现在落到了 idx
+            let moved_key = self.slab[idx].key.clone();
+            self.map.insert(moved_key, idx);
+            let (prev, next) = (self.slab[idx].prev, self.slab[idx].next);
+            match prev {
+                Some(p) => self.slab[p].next = Some(idx),
+                None => self.head = Some(idx),
+            }
+            match next {
+                Some(n) => self.slab[n].prev = Some(idx),
+                None => self.tail = Some(idx),
+            }
+        }
+
+        Some(removed.value)
+    }
+
+    // 当前条目数
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    // 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    // 从最近使用到最久未使用遍历
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            cache: self,
+            current: self.head,
+        }
+    }
+}
+
+// 从 MRU 到 LRU 的迭代器
+pub struct Iter<'a, K, V> {
+    cache: &'a LruCache<K, V>,
+    current: Option<usize>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.current?;
+        let node = &self.cache.slab[idx];
+        self.current = node.next;
+        Some((&node.key, &node.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "a");
+        cache.put(2, "b");
+        // 访问 1，使其成为最近使用；再插入 3 应淘汰 2
+        assert_eq!(cache.get(&1), Some(&"a"));
+        cache.put(3, "c");
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some(&"a"));
+        assert_eq!(cache.get(&3), Some(&"c"));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn peek_does_not_promote() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        assert_eq!(cache.peek(&1), Some(&1));
+        // 1 仍是最久未使用，插入 3 应淘汰它
+        cache.put(3, 3);
+        assert_eq!(cache.peek(&1), None);
+        assert_eq!(cache.peek(&2), Some(&2));
+    }
+
+    #[test]
+    fn pop_fixes_up_moved_slab_slot() {
+        let mut cache = LruCache::new(3);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        cache.put(3, 3);
+        // 弹出一个非末尾槽位，触发 swap_remove 的下标修正
+        assert_eq!(cache.pop(&1), Some(1));
+        assert_eq!(cache.len(), 2);
+        // 被移动的键仍能正确查到，链表顺序保持 MRU->LRU
+        let order: Vec<i32> = cache.iter().map(|(&k, _)| k).collect();
+        assert_eq!(order, [3, 2]);
+        assert_eq!(cache.get(&2), Some(&2));
+        assert_eq!(cache.get(&3), Some(&3));
+    }
+
+    #[test]
+    fn put_existing_overwrites_and_promotes() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, 1);
+        cache.put(2, 2);
+        assert_eq!(cache.put(1, 10), Some(1));
+        // 1 被提升为最近使用，插入 3 应淘汰 2
+        cache.put(3, 3);
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some(&10));
+    }
+}
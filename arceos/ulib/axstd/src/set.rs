@@ -0,0 +1,149 @@
+extern crate alloc;
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use crate::hashmap::HashMap;
+
+// 只关心成员归属的集合，是 `HashMap<T, ()>` 的轻量包装
+pub struct HashSet<T> {
+    map: HashMap<T, ()>,
+}
+
+impl<T> HashSet<T>
+where
+    T: Eq + Hash,
+{
+    // 创建一个空集合
+    pub fn new() -> Self {
+        HashSet {
+            map: HashMap::new(),
+        }
+    }
+
+    // 插入元素；若此前不存在返回 true
+    pub fn insert(&mut self, value: T) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    // 判断元素是否存在
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(value).is_some()
+    }
+
+    // 移除元素；若确实存在返回 true
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    // 元素个数
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    // 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    // 遍历所有元素
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.map.iter(),
+        }
+    }
+
+    // 并集：本集合的全部元素，加上另一集合中本集合没有的元素
+    pub fn union<'a>(&'a self, other: &'a HashSet<T>) -> impl Iterator<Item = &'a T> {
+        self.iter().chain(other.difference(self))
+    }
+
+    // 交集：同时属于两个集合的元素
+    pub fn intersection<'a>(&'a self, other: &'a HashSet<T>) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |v| other.contains(*v))
+    }
+
+    // 差集：属于本集合但不属于另一集合的元素
+    pub fn difference<'a>(&'a self, other: &'a HashSet<T>) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |v| !other.contains(*v))
+    }
+
+    // 对称差：只属于其中一个集合的元素
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a HashSet<T>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.difference(other).chain(other.difference(self))
+    }
+}
+
+impl<T> Default for HashSet<T>
+where
+    T: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// 集合的迭代器
+pub struct Iter<'a, T> {
+    inner: crate::hashmap::Iter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn set(items: &[i32]) -> HashSet<i32> {
+        let mut s = HashSet::new();
+        for &i in items {
+            s.insert(i);
+        }
+        s
+    }
+
+    fn sorted<'a, I: Iterator<Item = &'a i32>>(iter: I) -> Vec<i32> {
+        let mut v: Vec<i32> = iter.copied().collect();
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn insert_and_remove_report_membership() {
+        let mut s = HashSet::new();
+        assert!(s.insert(1));
+        assert!(!s.insert(1));
+        assert!(s.contains(&1));
+        assert!(s.remove(&1));
+        assert!(!s.remove(&1));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = set(&[1, 2, 3]);
+        let b = set(&[2, 3, 4]);
+        assert_eq!(sorted(a.union(&b)), [1, 2, 3, 4]);
+        assert_eq!(sorted(a.intersection(&b)), [2, 3]);
+        assert_eq!(sorted(a.difference(&b)), [1]);
+        assert_eq!(sorted(a.symmetric_difference(&b)), [1, 4]);
+    }
+}